@@ -1,27 +1,70 @@
-use std::time::Duration;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
-use sysinfo::{CpuRefreshKind, MemoryRefreshKind, ProcessRefreshKind, RefreshKind, System, Pid};
+use regex::{Regex, RegexBuilder};
+use sysinfo::{
+    CpuRefreshKind, Disks, MemoryRefreshKind, Networks, Pid, ProcessRefreshKind, RefreshKind, System,
+};
 
-#[derive(Copy, Clone)]
+use crate::config::Config;
+
+pub const HISTORY_LEN: usize = 300;
+
+pub struct NetworkRate {
+    pub interface: String,
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+    pub total_rx: u64,
+    pub total_tx: u64,
+}
+
+pub struct DiskInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub total_space: u64,
+    pub available_space: u64,
+}
+
+#[derive(Copy, Clone, PartialEq)]
 pub enum SortBy {
     Cpu,
     Mem,
     Pid,
+    Name,
 }
 
 pub struct App {
     pub sys: System,
+    networks: Networks,
+    disks: Disks,
     pub sort_by: SortBy,
+    pub ascending: bool,
     pub filter: String,
     pub is_filtering: bool,
+    pub use_regex: bool,
+    pub case_sensitive: bool,
+    pub is_invalid_search: bool,
+    filter_matcher: Option<Regex>,
+    filter_cache_key: Option<(String, bool, bool)>,
     pub show_help: bool,
     pub selected_index: usize,
     pub selected_pid: Option<Pid>,
     pub tick_rate: Duration,
+    pub kill_pending: Option<Pid>,
+    pub kill_pending_at: Option<Instant>,
+    pub show_kill_confirm: bool,
+    pub cpu_history: VecDeque<f64>,
+    pub mem_history: VecDeque<f64>,
+    pub net_rates: Vec<NetworkRate>,
+    pub disk_info: Vec<DiskInfo>,
+    last_net_totals: HashMap<String, (u64, u64)>,
+    last_net_refresh_at: Option<Instant>,
+    pub basic_mode: bool,
+    pub frozen: bool,
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         let mut sys = System::new_with_specifics(
             RefreshKind::new()
                 .with_cpu(CpuRefreshKind::everything())
@@ -31,16 +74,47 @@ impl App {
         sys.refresh_all();
         Self {
             sys,
-            sort_by: SortBy::Cpu,
+            networks: Networks::new_with_refreshed_list(),
+            disks: Disks::new_with_refreshed_list(),
+            sort_by: config.default_sort,
+            ascending: false,
             filter: String::new(),
             is_filtering: false,
+            use_regex: config.use_regex,
+            case_sensitive: config.case_sensitive,
+            is_invalid_search: false,
+            filter_matcher: None,
+            filter_cache_key: None,
             show_help: false,
             selected_index: 0,
             selected_pid: None,
-            tick_rate: Duration::from_millis(500),
+            tick_rate: config.tick_rate,
+            kill_pending: None,
+            kill_pending_at: None,
+            show_kill_confirm: false,
+            cpu_history: VecDeque::with_capacity(HISTORY_LEN),
+            mem_history: VecDeque::with_capacity(HISTORY_LEN),
+            net_rates: Vec::new(),
+            disk_info: Vec::new(),
+            last_net_totals: HashMap::new(),
+            last_net_refresh_at: None,
+            basic_mode: false,
+            frozen: false,
         }
     }
 
+    pub fn reset(&mut self) {
+        self.cpu_history.clear();
+        self.mem_history.clear();
+        self.net_rates.clear();
+        self.disk_info.clear();
+        self.last_net_totals.clear();
+        self.last_net_refresh_at = None;
+        self.sys.refresh_all();
+        self.networks.refresh_list();
+        self.disks.refresh_list();
+    }
+
     pub fn refresh(&mut self) {
         self.sys.refresh_specifics(
             RefreshKind::new()
@@ -48,7 +122,126 @@ impl App {
                 .with_memory(MemoryRefreshKind::everything())
                 .with_processes(ProcessRefreshKind::everything()),
         );
+        self.networks.refresh_list();
+        self.disks.refresh_list();
+        self.push_history();
+        self.update_net_rates();
+        self.update_disk_info();
     }
-}
 
+    fn update_net_rates(&mut self) {
+        let now = Instant::now();
+        let elapsed_secs = self
+            .last_net_refresh_at
+            .map(|at| now.duration_since(at).as_secs_f64())
+            .unwrap_or_else(|| self.tick_rate.as_secs_f64())
+            .max(f64::EPSILON);
+        self.last_net_refresh_at = Some(now);
+
+        let mut rates = Vec::new();
+        for (iface, data) in self.networks.iter() {
+            let total_rx = data.total_received();
+            let total_tx = data.total_transmitted();
+            let (prev_rx, prev_tx) = self
+                .last_net_totals
+                .get(iface)
+                .copied()
+                .unwrap_or((total_rx, total_tx));
+            rates.push(NetworkRate {
+                interface: iface.clone(),
+                rx_rate: total_rx.saturating_sub(prev_rx) as f64 / elapsed_secs,
+                tx_rate: total_tx.saturating_sub(prev_tx) as f64 / elapsed_secs,
+                total_rx,
+                total_tx,
+            });
+            self.last_net_totals.insert(iface.clone(), (total_rx, total_tx));
+        }
+        rates.sort_by(|a, b| a.interface.cmp(&b.interface));
+        self.net_rates = rates;
+    }
+
+    // sysinfo only exposes disk space, not I/O throughput (that's a per-process
+    // stat via Process::disk_usage()), so this is space-only, not rates.
+    fn update_disk_info(&mut self) {
+        self.disk_info = self
+            .disks
+            .iter()
+            .map(|disk| DiskInfo {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+            })
+            .collect();
+    }
+
+    fn push_history(&mut self) {
+        let cpu = self.sys.global_cpu_info().cpu_usage() as f64;
+        let total_mem = self.sys.total_memory();
+        let mem_percent = if total_mem == 0 {
+            0.0
+        } else {
+            (self.sys.used_memory() as f64 / total_mem as f64) * 100.0
+        };
+
+        if self.cpu_history.len() == HISTORY_LEN {
+            self.cpu_history.pop_front();
+        }
+        self.cpu_history.push_back(cpu);
+
+        if self.mem_history.len() == HISTORY_LEN {
+            self.mem_history.pop_front();
+        }
+        self.mem_history.push_back(mem_percent);
+    }
+
+    pub fn refresh_filter_matcher(&mut self) {
+        let key = (self.filter.clone(), self.use_regex, self.case_sensitive);
+        if self.filter_cache_key.as_ref() == Some(&key) {
+            return;
+        }
+        self.filter_cache_key = Some(key);
 
+        if !self.use_regex {
+            self.is_invalid_search = false;
+            return;
+        }
+
+        match RegexBuilder::new(&self.filter)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+        {
+            Ok(re) => {
+                self.filter_matcher = Some(re);
+                self.is_invalid_search = false;
+            }
+            // Keep the last valid matcher so the list doesn't go blank mid-edit.
+            Err(_) => self.is_invalid_search = true,
+        }
+    }
+
+    pub fn set_sort(&mut self, sort_by: SortBy) {
+        if self.sort_by == sort_by {
+            self.ascending = !self.ascending;
+        } else {
+            self.ascending = matches!(sort_by, SortBy::Name);
+            self.sort_by = sort_by;
+        }
+    }
+
+    pub fn filter_matches(&self, name: &str) -> bool {
+        if self.filter.is_empty() {
+            return true;
+        }
+        if self.use_regex {
+            match &self.filter_matcher {
+                Some(re) => re.is_match(name),
+                None => true,
+            }
+        } else if self.case_sensitive {
+            name.contains(&self.filter)
+        } else {
+            name.to_lowercase().contains(&self.filter.to_lowercase())
+        }
+    }
+}
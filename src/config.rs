@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::app::SortBy;
+
+const DEFAULT_CONFIG_TEMPLATE: &str = r#"# rust-top configuration
+# Uncomment and edit any of the following to change startup defaults.
+
+# Refresh interval in milliseconds.
+# tick_rate_ms = 500
+
+# Default sort column: "cpu", "mem", "pid", or "name".
+# default_sort = "cpu"
+
+# Whether name filtering is case-sensitive by default.
+# case_sensitive = false
+
+# Whether the name filter is treated as a regex by default.
+# use_regex = false
+"#;
+
+#[derive(Debug, Default, Deserialize)]
+struct RawConfig {
+    tick_rate_ms: Option<u64>,
+    default_sort: Option<String>,
+    case_sensitive: Option<bool>,
+    use_regex: Option<bool>,
+}
+
+pub struct Config {
+    pub tick_rate: Duration,
+    pub default_sort: SortBy,
+    pub case_sensitive: bool,
+    pub use_regex: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            tick_rate: Duration::from_millis(500),
+            default_sort: SortBy::Cpu,
+            case_sensitive: false,
+            use_regex: false,
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: Option<PathBuf>) -> Result<Self> {
+        let path = path.unwrap_or_else(default_config_path);
+
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("failed to create config dir {}", parent.display()))?;
+            }
+            fs::write(&path, DEFAULT_CONFIG_TEMPLATE)
+                .with_context(|| format!("failed to write default config to {}", path.display()))?;
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        let raw: RawConfig = toml::from_str(&contents)
+            .with_context(|| format!("failed to parse config file {}", path.display()))?;
+
+        let mut config = Self::default();
+        if let Some(ms) = raw.tick_rate_ms {
+            config.tick_rate = Duration::from_millis(ms);
+        }
+        if let Some(sort) = raw.default_sort {
+            config.default_sort = parse_sort_by(&sort)?;
+        }
+        if let Some(case_sensitive) = raw.case_sensitive {
+            config.case_sensitive = case_sensitive;
+        }
+        if let Some(use_regex) = raw.use_regex {
+            config.use_regex = use_regex;
+        }
+        Ok(config)
+    }
+}
+
+fn parse_sort_by(value: &str) -> Result<SortBy> {
+    match value.to_lowercase().as_str() {
+        "cpu" => Ok(SortBy::Cpu),
+        "mem" => Ok(SortBy::Mem),
+        "pid" => Ok(SortBy::Pid),
+        "name" => Ok(SortBy::Name),
+        other => anyhow::bail!("invalid default_sort {other:?}, expected \"cpu\", \"mem\", \"pid\", or \"name\""),
+    }
+}
+
+fn default_config_path() -> PathBuf {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("rust-top").join("config.toml")
+}
@@ -1,4 +1,7 @@
-use std::{io, time::Duration};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 use crossterm::{
@@ -9,16 +12,20 @@ use crossterm::{
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 use crate::app::{App, SortBy};
+use crate::config::Config;
 use crate::ui::ui;
 
-pub fn run() -> Result<()> {
+const KILL_CONFIRM_WINDOW: Duration = Duration::from_millis(700);
+
+pub fn run(config: Config, basic_mode: bool) -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new();
+    let mut app = App::new(config);
+    app.basic_mode = basic_mode;
 
     let res = run_app(&mut terminal, &mut app);
 
@@ -34,7 +41,9 @@ pub fn run() -> Result<()> {
 
 fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
     loop {
-        app.refresh();
+        if !app.frozen {
+            app.refresh();
+        }
         terminal.draw(|f| ui(f, app))?;
 
         if event::poll(app.tick_rate)? {
@@ -43,19 +52,49 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
                     if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
                         return Ok(());
                     }
-                    if app.is_filtering {
+                    if app.show_kill_confirm {
                         match key.code {
-                            KeyCode::Enter | KeyCode::Esc => {
-                                app.is_filtering = false;
+                            KeyCode::Char('y') | KeyCode::Enter => {
+                                if let Some(pid) = app.kill_pending {
+                                    if let Some(proc_) = app.sys.process(pid) {
+                                        proc_.kill();
+                                    }
+                                }
+                                app.show_kill_confirm = false;
+                                app.kill_pending = None;
+                                app.kill_pending_at = None;
+                                if !app.frozen {
+                                    app.refresh();
+                                }
                             }
-                            KeyCode::Backspace => {
-                                app.filter.pop();
-                            }
-                            KeyCode::Char(ch) => {
-                                app.filter.push(ch);
+                            KeyCode::Char('n') | KeyCode::Esc => {
+                                app.show_kill_confirm = false;
+                                app.kill_pending = None;
+                                app.kill_pending_at = None;
                             }
                             _ => {}
                         }
+                    } else if app.is_filtering {
+                        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('r') {
+                            app.use_regex = !app.use_regex;
+                        } else if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('s') {
+                            app.case_sensitive = !app.case_sensitive;
+                        } else {
+                            match key.code {
+                                KeyCode::Enter | KeyCode::Esc => {
+                                    app.is_filtering = false;
+                                }
+                                KeyCode::Backspace => {
+                                    app.filter.pop();
+                                }
+                                KeyCode::Char(ch) => {
+                                    app.filter.push(ch);
+                                }
+                                _ => {}
+                            }
+                        }
+                    } else if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('r') {
+                        app.reset();
                     } else {
                         match key.code {
                             KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
@@ -80,6 +119,12 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
                             KeyCode::Char('?') => {
                                 app.show_help = !app.show_help;
                             }
+                            KeyCode::Char('b') => {
+                                app.basic_mode = !app.basic_mode;
+                            }
+                            KeyCode::Char('f') => {
+                                app.frozen = !app.frozen;
+                            }
                             KeyCode::Char('+') => {
                                 let ms = (app.tick_rate.as_millis() as u64).saturating_sub(50).max(100);
                                 app.tick_rate = Duration::from_millis(ms);
@@ -88,9 +133,24 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
                                 let ms = (app.tick_rate.as_millis() as u64).saturating_add(50).min(2000);
                                 app.tick_rate = Duration::from_millis(ms);
                             }
-                            KeyCode::Char('c') => app.sort_by = SortBy::Cpu,
-                            KeyCode::Char('m') => app.sort_by = SortBy::Mem,
-                            KeyCode::Char('p') => app.sort_by = SortBy::Pid,
+                            KeyCode::Char('c') => app.set_sort(SortBy::Cpu),
+                            KeyCode::Char('m') => app.set_sort(SortBy::Mem),
+                            KeyCode::Char('p') => app.set_sort(SortBy::Pid),
+                            KeyCode::Char('n') => app.set_sort(SortBy::Name),
+                            KeyCode::Char('d') => {
+                                let now = Instant::now();
+                                let armed = app.selected_pid.is_some()
+                                    && app.kill_pending == app.selected_pid
+                                    && app
+                                        .kill_pending_at
+                                        .is_some_and(|at| now.duration_since(at) < KILL_CONFIRM_WINDOW);
+                                if armed {
+                                    app.show_kill_confirm = true;
+                                } else {
+                                    app.kill_pending = app.selected_pid;
+                                    app.kill_pending_at = Some(now);
+                                }
+                            }
                             _ => {}
                         }
                     }
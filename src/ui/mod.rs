@@ -1,8 +1,10 @@
+use std::collections::VecDeque;
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Gauge, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, Paragraph, Sparkline, Wrap},
     Frame,
 };
 // sysinfo re-exports used via fully-qualified paths below; no trait imports needed
@@ -10,32 +12,64 @@ use ratatui::{
 use crate::app::{App, SortBy};
 
 pub fn ui(frame: &mut Frame, app: &mut App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3),
-            Constraint::Min(7),
-            Constraint::Min(5),
-            Constraint::Length(1),
-        ])
-        .split(frame.size());
+    if app.basic_mode {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // header
+                Constraint::Length(3), // condensed system
+                Constraint::Min(5),    // processes get the rest
+                Constraint::Length(1), // footer
+            ])
+            .split(frame.size());
 
-    render_header(frame, chunks[0]);
-    render_system(frame, chunks[1], app);
-    render_processes(frame, chunks[2], app);
-    render_footer(frame, chunks[3]);
+        render_header(frame, chunks[0], app);
+        render_system_basic(frame, chunks[1], app);
+        render_processes(frame, chunks[2], app);
+        render_footer(frame, chunks[3]);
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(7),
+                Constraint::Length(7),
+                Constraint::Length(7),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(frame.size());
+
+        render_header(frame, chunks[0], app);
+        render_system(frame, chunks[1], app);
+        render_network(frame, chunks[2], app);
+        render_disks(frame, chunks[3], app);
+        render_processes(frame, chunks[4], app);
+        render_footer(frame, chunks[5]);
+    }
 
     if app.show_help {
         render_help_popup(frame);
     }
+
+    if app.show_kill_confirm {
+        render_kill_confirm_popup(frame, app);
+    }
 }
 
-fn render_header(frame: &mut Frame, area: Rect) {
-    let title = Paragraph::new(Line::from(vec![
+fn render_header(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![
         Span::styled("rust-top ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::raw("- q: quit, j/k: nav, c/m/p: sort by cpu/mem/pid, /: filter"),
-    ]))
-    .block(Block::default().borders(Borders::ALL).title("Overview"));
+        Span::raw("- q: quit, j/k: nav, c/m/p/n: sort by cpu/mem/pid/name (press again to reverse), /: filter"),
+    ];
+    if app.frozen {
+        spans.push(Span::styled(
+            "  [FROZEN]",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+    let title = Paragraph::new(Line::from(spans))
+        .block(Block::default().borders(Borders::ALL).title("Overview"));
     frame.render_widget(title, area);
 }
 
@@ -67,18 +101,45 @@ fn render_system(frame: &mut Frame, area: Rect, app: &App) {
     .wrap(Wrap { trim: true });
     frame.render_widget(summary, sys_chunks[0]);
 
+    let body_cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(sys_chunks[1]);
+
     let per_core = &app.sys.cpus();
     if !per_core.is_empty() {
         let cols = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(sys_chunks[1]);
+            .split(body_cols[0]);
         let half = (per_core.len() + 1) / 2;
         let left = &per_core[..half];
         let right = &per_core[half..];
         render_cpu_column(frame, cols[0], 0, left);
         render_cpu_column(frame, cols[1], half, right);
     }
+
+    render_history(frame, body_cols[1], app);
+}
+
+fn render_history(frame: &mut Frame, area: Rect, app: &App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    render_sparkline(frame, rows[0], "CPU History", &app.cpu_history, Color::Green);
+    render_sparkline(frame, rows[1], "Mem History", &app.mem_history, Color::Magenta);
+}
+
+fn render_sparkline(frame: &mut Frame, area: Rect, title: &str, history: &VecDeque<f64>, color: Color) {
+    let data: Vec<u64> = history.iter().map(|v| v.round() as u64).collect();
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&data)
+        .max(100)
+        .style(Style::default().fg(color));
+    frame.render_widget(sparkline, area);
 }
 
 fn render_cpu_column(frame: &mut Frame, area: Rect, offset: usize, cpus: &[sysinfo::Cpu]) {
@@ -102,7 +163,103 @@ fn render_cpu_column(frame: &mut Frame, area: Rect, offset: usize, cpus: &[sysin
     }
 }
 
+fn render_system_basic(frame: &mut Frame, area: Rect, app: &App) {
+    let total_mem = app.sys.total_memory();
+    let used_mem = app.sys.used_memory();
+    let mem_percent = if total_mem == 0 { 0.0 } else { (used_mem as f64 / total_mem as f64) * 100.0 };
+    let global_cpu = app.sys.global_cpu_info().cpu_usage();
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("CPU: ", Style::default().fg(Color::Yellow)),
+            Span::raw(format!("{global_cpu:.1}%")),
+        ]),
+        Line::from(vec![
+            Span::styled("Mem: ", Style::default().fg(Color::Yellow)),
+            Span::raw(format!("{:.1}% ({} / {} MiB)", mem_percent, used_mem / 1024 / 1024, total_mem / 1024 / 1024)),
+        ]),
+    ];
+    let p = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("System"))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(p, area);
+}
+
+fn render_network(frame: &mut Frame, area: Rect, app: &App) {
+    let header = ListItem::new(Line::from(vec![Span::styled(
+        format!(
+            "{:<14}  {:>10}  {:>10}  {:>12}  {:>12}",
+            "IFACE", "RX/s", "TX/s", "TOTAL RX", "TOTAL TX"
+        ),
+        Style::default().add_modifier(Modifier::BOLD),
+    )]));
+
+    let mut items: Vec<ListItem> = Vec::with_capacity(app.net_rates.len() + 1);
+    items.push(header);
+    for rate in &app.net_rates {
+        items.push(ListItem::new(Line::from(vec![Span::raw(format!(
+            "{:<14}  {:>10}  {:>10}  {:>12}  {:>12}",
+            rate.interface,
+            format_rate(rate.rx_rate),
+            format_rate(rate.tx_rate),
+            format_bytes(rate.total_rx as f64),
+            format_bytes(rate.total_tx as f64),
+        ))])));
+    }
+
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title("Network"));
+    frame.render_widget(list, area);
+}
+
+fn render_disks(frame: &mut Frame, area: Rect, app: &App) {
+    let header = ListItem::new(Line::from(vec![Span::styled(
+        format!(
+            "{:<10}  {:<14}  {:>10}  {:>10}  {:>10}",
+            "DISK", "MOUNT", "USED", "FREE", "TOTAL"
+        ),
+        Style::default().add_modifier(Modifier::BOLD),
+    )]));
+
+    let mut items: Vec<ListItem> = Vec::with_capacity(app.disk_info.len() + 1);
+    items.push(header);
+    for disk in &app.disk_info {
+        let used = disk.total_space.saturating_sub(disk.available_space);
+        items.push(ListItem::new(Line::from(vec![Span::raw(format!(
+            "{:<10}  {:<14}  {:>10}  {:>10}  {:>10}",
+            disk.name,
+            disk.mount_point,
+            format_bytes(used as f64),
+            format_bytes(disk.available_space as f64),
+            format_bytes(disk.total_space as f64),
+        ))])));
+    }
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Disks (space only, no sysinfo throughput API)"),
+    );
+    frame.render_widget(list, area);
+}
+
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+fn format_rate(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_bytes(bytes_per_sec))
+}
+
 fn render_processes(frame: &mut Frame, area: Rect, app: &mut App) {
+    app.refresh_filter_matcher();
+
     let mut processes: Vec<_> = app
         .sys
         .processes()
@@ -110,15 +267,19 @@ fn render_processes(frame: &mut Frame, area: Rect, app: &mut App) {
         .map(|(pid, p)| (*pid, p))
         .collect();
 
-    match app.sort_by {
-        SortBy::Cpu => processes.sort_by(|a, b| b.1.cpu_usage().total_cmp(&a.1.cpu_usage())),
-        SortBy::Mem => processes.sort_by(|a, b| b.1.memory().cmp(&a.1.memory())),
-        SortBy::Pid => processes.sort_by(|a, b| b.0.cmp(&a.0)),
+    match (app.sort_by, app.ascending) {
+        (SortBy::Cpu, false) => processes.sort_by(|a, b| b.1.cpu_usage().total_cmp(&a.1.cpu_usage())),
+        (SortBy::Cpu, true) => processes.sort_by(|a, b| a.1.cpu_usage().total_cmp(&b.1.cpu_usage())),
+        (SortBy::Mem, false) => processes.sort_by(|a, b| b.1.memory().cmp(&a.1.memory())),
+        (SortBy::Mem, true) => processes.sort_by(|a, b| a.1.memory().cmp(&b.1.memory())),
+        (SortBy::Pid, false) => processes.sort_by(|a, b| b.0.cmp(&a.0)),
+        (SortBy::Pid, true) => processes.sort_by(|a, b| a.0.cmp(&b.0)),
+        (SortBy::Name, false) => processes.sort_by(|a, b| b.1.name().cmp(a.1.name())),
+        (SortBy::Name, true) => processes.sort_by(|a, b| a.1.name().cmp(b.1.name())),
     }
 
-    let filter_lower = app.filter.to_lowercase();
-    if !filter_lower.is_empty() {
-        processes.retain(|(_, p)| p.name().to_string().to_lowercase().contains(&filter_lower));
+    if !app.filter.is_empty() {
+        processes.retain(|(_, p)| app.filter_matches(p.name()));
     }
 
     if let Some(sel_pid) = app.selected_pid {
@@ -133,8 +294,22 @@ fn render_processes(frame: &mut Frame, area: Rect, app: &mut App) {
         app.selected_pid = Some(*pid);
     }
 
+    let arrow = if app.ascending { "\u{25b2}" } else { "\u{25bc}" };
+    let mut pid_label = "PID".to_string();
+    let mut cpu_label = "%CPU".to_string();
+    let mut mem_label = "MEM".to_string();
+    let mut name_label = "NAME".to_string();
+    match app.sort_by {
+        SortBy::Pid => pid_label = format!("PID{arrow}"),
+        SortBy::Cpu => cpu_label = format!("%CPU{arrow}"),
+        SortBy::Mem => mem_label = format!("MEM{arrow}"),
+        SortBy::Name => name_label = format!("NAME{arrow}"),
+    }
     let header = ListItem::new(Line::from(vec![
-        Span::styled(format!("{:>6}  {:>5}  {:>6}  {}", "PID", "%CPU", "MEM", "NAME"), Style::default().add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!("{:>6}  {:>5}  {:>6}  {}", pid_label, cpu_label, mem_label, name_label),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
     ]));
 
     let mut items: Vec<ListItem> = Vec::with_capacity(processes.len() + 1);
@@ -153,9 +328,30 @@ fn render_processes(frame: &mut Frame, area: Rect, app: &mut App) {
     let title_text = if app.filter.is_empty() {
         "Processes".to_string()
     } else {
-        format!("Processes | filter: {}{}", app.filter, if app.is_filtering { "_" } else { "" })
+        let mode = match (app.use_regex, app.case_sensitive) {
+            (true, true) => "regex,case",
+            (true, false) => "regex",
+            (false, true) => "case",
+            (false, false) => "",
+        };
+        let mode_suffix = if mode.is_empty() { String::new() } else { format!(" [{mode}]") };
+        format!(
+            "Processes | filter: {}{}{}",
+            app.filter,
+            if app.is_filtering { "_" } else { "" },
+            mode_suffix,
+        )
+    };
+    let title_style = if app.is_invalid_search {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
     };
-    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title_text));
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(title_text, title_style)),
+    );
     frame.render_widget(list, area);
 }
 
@@ -166,14 +362,22 @@ fn render_footer(frame: &mut Frame, area: Rect) {
         Span::raw(" move, "),
         Span::styled("PgUp/PgDn, g/G", Style::default().fg(Color::Green)),
         Span::raw(" jump, "),
-        Span::styled("c/m/p", Style::default().fg(Color::Green)),
-        Span::raw(" sort, "),
+        Span::styled("c/m/p/n", Style::default().fg(Color::Green)),
+        Span::raw(" sort (again to reverse), "),
         Span::styled("/", Style::default().fg(Color::Green)),
         Span::raw(" filter, "),
         Span::styled("?", Style::default().fg(Color::Green)),
         Span::raw(" help, "),
         Span::styled("+/-", Style::default().fg(Color::Green)),
-        Span::raw(" tick. "),
+        Span::raw(" tick, "),
+        Span::styled("dd", Style::default().fg(Color::Green)),
+        Span::raw(" kill, "),
+        Span::styled("b", Style::default().fg(Color::Green)),
+        Span::raw(" basic mode, "),
+        Span::styled("f", Style::default().fg(Color::Green)),
+        Span::raw(" freeze, "),
+        Span::styled("Ctrl-R", Style::default().fg(Color::Green)),
+        Span::raw(" reset. "),
     ];
     let text = Line::from(parts);
     let p = Paragraph::new(text).block(Block::default().borders(Borders::ALL));
@@ -188,15 +392,43 @@ fn render_help_popup(frame: &mut Frame) {
         Line::from("  q/Esc/Ctrl-C: quit"),
         Line::from("  j/k or arrows: move selection"),
         Line::from("  PgUp/PgDn, g/G: page/top/bottom"),
-        Line::from("  c/m/p: sort by CPU/mem/PID"),
+        Line::from("  c/m/p/n: sort by CPU/mem/PID/name; press the same key again to reverse"),
         Line::from("  / then type: filter by name; Enter/Esc to finish"),
+        Line::from("  Ctrl-R: toggle regex filter, Ctrl-S: toggle case-sensitive (while filtering)"),
         Line::from("  +/-: adjust refresh rate"),
+        Line::from("  b: toggle basic (compact, graph-free) mode"),
+        Line::from("  f: freeze/unfreeze the display"),
+        Line::from("  Ctrl-R (when not filtering): reset accumulated history and force a full refresh"),
         Line::from("  ?: toggle this help"),
+        Line::from(""),
+        Line::from("Disks panel shows space only: sysinfo has no per-disk I/O throughput API."),
     ])
     .block(Block::default().borders(Borders::ALL).title("Help"));
     frame.render_widget(help, area);
 }
 
+fn render_kill_confirm_popup(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, area);
+
+    let (pid_text, name) = match app
+        .kill_pending
+        .and_then(|pid| app.sys.process(pid).map(|p| (pid, p.name().to_string())))
+    {
+        Some((pid, name)) => (format!("{pid}"), name),
+        None => ("?".to_string(), "unknown".to_string()),
+    };
+
+    let body = Paragraph::new(vec![
+        Line::from(format!("Kill process {pid_text} ({name})?")),
+        Line::from(""),
+        Line::from("y/Enter: confirm    n/Esc: cancel"),
+    ])
+    .block(Block::default().borders(Borders::ALL).title("Confirm Kill"))
+    .wrap(Wrap { trim: true });
+    frame.render_widget(body, area);
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)